@@ -0,0 +1,158 @@
+//! `#[derive(Patchable)]` for `rust-json-patch`.
+//!
+//! Given an event struct whose fields are `JsonPatch<T>`/`JsonTernary<T>`, this
+//! generates an `Apply<Event>` impl for the record struct named via
+//! `#[patch(target = "...")]`, so that `record.apply(&event)` replaces the
+//! hand-written match block over every field.
+//!
+//! Per-field attributes:
+//! * `#[patch(rename = "other_field")]` maps an event field to a
+//!   differently-named record field.
+//! * `#[patch(skip)]` leaves the field out of the generated `apply` body.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, Lit, Meta, NestedMeta};
+
+#[proc_macro_derive(Patchable, attributes(patch))]
+pub fn derive_patchable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let event_ident = &input.ident;
+
+    let target_ident = match target_type(&input.attrs) {
+        Ok(Some(ident)) => ident,
+        Ok(None) => {
+            return syn::Error::new_spanned(
+                &input,
+                "#[derive(Patchable)] requires #[patch(target = \"RecordName\")] on the event struct",
+            )
+            .to_compile_error()
+            .into()
+        }
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let fields = match named_fields(&input.data) {
+        Ok(fields) => fields,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let mut arms = Vec::new();
+    for field in fields {
+        let field_ident = field.ident.as_ref().expect("named field");
+        let field_attrs = match FieldAttrs::parse(&field.attrs) {
+            Ok(attrs) => attrs,
+            Err(err) => return err.to_compile_error().into(),
+        };
+        if field_attrs.skip {
+            continue;
+        }
+        let target_field = field_attrs.rename.unwrap_or_else(|| field_ident.clone());
+        let tristate_path = match tristate_path(&field.ty) {
+            Ok(path) => path,
+            Err(err) => return err.to_compile_error().into(),
+        };
+        arms.push(quote! {
+            match &event.#field_ident {
+                #tristate_path::Value(value) => {
+                    self.#target_field = ::core::convert::Into::into(value.clone());
+                }
+                #tristate_path::Null => {
+                    self.#target_field = ::core::default::Default::default();
+                }
+                #tristate_path::Absent => (),
+            }
+        });
+    }
+
+    let expanded = quote! {
+        impl rust_json_patch::Apply<#event_ident> for #target_ident {
+            fn apply(&mut self, event: &#event_ident) {
+                #(#arms)*
+            }
+        }
+    };
+    expanded.into()
+}
+
+/// Strips the generic argument off a field's `JsonPatch<T>`/`JsonTernary<T>`
+/// type, leaving a bare path usable to match its `Value`/`Null`/`Absent`
+/// variants. Works for either tri-state type (or any type alias to one)
+/// since only the variant names, not the concrete enum, matter here.
+fn tristate_path(ty: &syn::Type) -> syn::Result<syn::Path> {
+    match ty {
+        syn::Type::Path(type_path) => {
+            let mut path = type_path.path.clone();
+            if let Some(last) = path.segments.last_mut() {
+                last.arguments = syn::PathArguments::None;
+            }
+            Ok(path)
+        }
+        _ => Err(syn::Error::new_spanned(ty, "Patchable fields must be JsonPatch<T> or JsonTernary<T>")),
+    }
+}
+
+fn named_fields(data: &Data) -> syn::Result<&syn::punctuated::Punctuated<syn::Field, syn::token::Comma>> {
+    match data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(&fields.named),
+            _ => Err(syn::Error::new_spanned(data.fields.clone(), "Patchable only supports structs with named fields")),
+        },
+        _ => Err(syn::Error::new(proc_macro2::Span::call_site(), "Patchable can only be derived for structs")),
+    }
+}
+
+fn target_type(attrs: &[syn::Attribute]) -> syn::Result<Option<Ident>> {
+    for attr in attrs {
+        if !attr.path.is_ident("patch") {
+            continue;
+        }
+        if let Meta::List(list) = attr.parse_meta()? {
+            for nested in list.nested {
+                if let NestedMeta::Meta(Meta::NameValue(nv)) = nested {
+                    if nv.path.is_ident("target") {
+                        if let Lit::Str(s) = nv.lit {
+                            return Ok(Some(Ident::new(&s.value(), s.span())));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    rename: Option<Ident>,
+    skip: bool,
+}
+
+impl FieldAttrs {
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut result = FieldAttrs::default();
+        for attr in attrs {
+            if !attr.path.is_ident("patch") {
+                continue;
+            }
+            if let Meta::List(list) = attr.parse_meta()? {
+                for nested in list.nested {
+                    match nested {
+                        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => {
+                            result.skip = true;
+                        }
+                        NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("rename") => {
+                            if let Lit::Str(s) = nv.lit {
+                                result.rename = Some(Ident::new(&s.value(), s.span()));
+                            }
+                        }
+                        other => {
+                            return Err(syn::Error::new_spanned(other, "unsupported #[patch(...)] attribute"));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,63 @@
+/// Applies the changes described by a patch event onto `self`.
+///
+/// Hand-written consumers typically `match` over each `JsonPatch`/`JsonTernary`
+/// field of `Event`: overwrite the target field on `Value`, reset it to
+/// `Default::default()` (or `None`) on `Null`, and leave it untouched on
+/// `Absent`. `#[derive(Patchable)]` (see `rust-json-patch-derive`) generates
+/// exactly this impl for a target/event struct pair, so callers only write
+/// `record.apply(&event)`.
+pub trait Apply<Event> {
+    fn apply(&mut self, event: &Event);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Apply;
+    use crate::json_patch::JsonPatch;
+
+    #[derive(Debug, Default, PartialEq)]
+    struct PersonRecord {
+        name: String,
+        family_name: String,
+    }
+
+    struct PersonEvent {
+        name: JsonPatch<String>,
+        family_name: JsonPatch<String>,
+    }
+
+    impl Apply<PersonEvent> for PersonRecord {
+        fn apply(&mut self, event: &PersonEvent) {
+            match &event.name {
+                JsonPatch::Value(v) => self.name = v.clone(),
+                JsonPatch::Null => self.name = Default::default(),
+                JsonPatch::Absent => (),
+            }
+            match &event.family_name {
+                JsonPatch::Value(v) => self.family_name = v.clone(),
+                JsonPatch::Null => self.family_name = Default::default(),
+                JsonPatch::Absent => (),
+            }
+        }
+    }
+
+    #[test]
+    fn test_apply_overwrites_value_and_skips_absent() {
+        let mut record = PersonRecord { name: String::from("John"), family_name: String::from("Doe") };
+        let event = PersonEvent { name: JsonPatch::Absent, family_name: JsonPatch::Value(String::from("Deer")) };
+
+        record.apply(&event);
+
+        assert_eq!(record, PersonRecord { name: String::from("John"), family_name: String::from("Deer") });
+    }
+
+    #[test]
+    fn test_apply_resets_on_null() {
+        let mut record = PersonRecord { name: String::from("John"), family_name: String::from("Doe") };
+        let event = PersonEvent { name: JsonPatch::Absent, family_name: JsonPatch::Null };
+
+        record.apply(&event);
+
+        assert_eq!(record, PersonRecord { name: String::from("John"), family_name: String::new() });
+    }
+}
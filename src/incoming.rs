@@ -0,0 +1,107 @@
+//! Resilient deserialization with a dynamic fallback.
+//!
+//! `serde_json::from_str::<Message>` fails the whole parse if a newer
+//! producer sends a shape the consumer doesn't recognize yet, losing the
+//! message. `Incoming<T>` tries the strongly-typed `T` first and falls back
+//! to the raw [`serde_json::Value`] on failure, so the caller can route
+//! unrecognized events to a dead-letter path instead of crashing.
+
+use serde::de::{Deserialize, DeserializeOwned, Deserializer};
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::json_patch::JsonPatch;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Incoming<T> {
+    Typed(T),
+    Dynamic(Value),
+}
+
+impl<T> Incoming<T> {
+    pub const fn is_typed(&self) -> bool {
+        matches!(self, Incoming::Typed(_))
+    }
+    pub const fn is_dynamic(&self) -> bool {
+        matches!(self, Incoming::Dynamic(_))
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Incoming<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        match serde_json::from_value::<T>(value.clone()) {
+            Ok(typed) => Ok(Incoming::Typed(typed)),
+            Err(_) => Ok(Incoming::Dynamic(value)),
+        }
+    }
+}
+
+/// Unknown fields of an event, captured via `#[serde(flatten)]` as
+/// `JsonPatch<Value>` so they round-trip through `Value`/`Null`/`Absent`
+/// and are re-serialized instead of being silently dropped:
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct PersonEvent {
+///     name: JsonPatch<String>,
+///     #[serde(flatten)]
+///     extras: PatchExtras,
+/// }
+/// ```
+pub type PatchExtras = HashMap<String, JsonPatch<Value>>;
+
+#[cfg(test)]
+mod tests {
+    use super::{Incoming, PatchExtras};
+    use crate::json_patch::JsonPatch;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct PersonEvent {
+        name: String,
+    }
+
+    #[test]
+    fn test_incoming_parses_recognized_shape_as_typed() {
+        let incoming: Incoming<PersonEvent> = serde_json::from_value(json!({"name": "John"})).unwrap();
+        assert!(incoming.is_typed());
+        assert_eq!(incoming, Incoming::Typed(PersonEvent { name: String::from("John") }));
+    }
+
+    #[test]
+    fn test_incoming_falls_back_to_dynamic_on_unrecognized_shape() {
+        let incoming: Incoming<PersonEvent> = serde_json::from_value(json!({"firstName": "John"})).unwrap();
+        assert!(incoming.is_dynamic());
+        assert_eq!(incoming, Incoming::Dynamic(json!({"firstName": "John"})));
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct RecordWithExtras {
+        #[serde(default)]
+        #[serde(skip_serializing_if = "JsonPatch::is_absent")]
+        name: JsonPatch<String>,
+
+        #[serde(flatten)]
+        extras: PatchExtras,
+    }
+
+    #[test]
+    fn test_patch_extras_round_trips_unknown_fields() {
+        let json = r#"{"name":"John","nickname":"Johnny","note":null}"#;
+        let record: RecordWithExtras = serde_json::from_str(json).unwrap();
+
+        assert_eq!(record.extras.get("nickname"), Some(&JsonPatch::Value(json!("Johnny"))));
+        assert_eq!(record.extras.get("note"), Some(&JsonPatch::Null));
+
+        let reserialized = serde_json::to_string(&record).unwrap();
+        let reparsed: RecordWithExtras = serde_json::from_str(&reserialized).unwrap();
+        assert_eq!(reparsed, record);
+    }
+}
@@ -19,6 +19,9 @@ impl<T> JsonPatch<T> {
     }
 }
 
+// See src/tristate.rs for map/and_then/as_ref/as_mut/unwrap_or/unwrap_or_else/value/into_option/into_nested/from_nested
+crate::impl_tristate_combinators!(JsonPatch);
+
 // https://stackoverflow.com/a/44332837
 impl<T> Default for JsonPatch<T> {
     fn default() -> Self {
@@ -141,6 +144,35 @@ mod tests {
         serde_and_verify(&record_ref, json_ref);
     }
 
+    #[test]
+    pub fn test_map_transforms_value_and_leaves_null_absent() {
+        assert_eq!(JsonPatch::Value(2).map(|v| v * 10), JsonPatch::Value(20));
+        assert_eq!(JsonPatch::<i32>::Null.map(|v| v * 10), JsonPatch::Null);
+        assert_eq!(JsonPatch::<i32>::Absent.map(|v| v * 10), JsonPatch::Absent);
+    }
+
+    #[test]
+    pub fn test_unwrap_or_and_into_option() {
+        assert_eq!(JsonPatch::Value(2).unwrap_or(0), 2);
+        assert_eq!(JsonPatch::<i32>::Null.unwrap_or(0), 0);
+        assert_eq!(JsonPatch::Value(2).into_option(), Some(2));
+        assert_eq!(JsonPatch::<i32>::Absent.into_option(), None);
+    }
+
+    #[test]
+    pub fn test_into_nested_distinguishes_null_from_absent() {
+        assert_eq!(JsonPatch::Value(2).into_nested(), Some(Some(2)));
+        assert_eq!(JsonPatch::<i32>::Null.into_nested(), Some(None));
+        assert_eq!(JsonPatch::<i32>::Absent.into_nested(), None);
+    }
+
+    #[test]
+    pub fn test_from_nested_round_trips_into_nested() {
+        assert_eq!(JsonPatch::from_nested(Some(Some(2))), JsonPatch::Value(2));
+        assert_eq!(JsonPatch::<i32>::from_nested(Some(None)), JsonPatch::Null);
+        assert_eq!(JsonPatch::<i32>::from_nested(None), JsonPatch::Absent);
+    }
+
     fn serde_and_verify(record_ref: &Record, json_ref: &str) {
         let json = serde_json::to_string(&record_ref);
         assert!(json.is_ok());
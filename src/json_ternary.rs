@@ -19,6 +19,9 @@ impl<T> JsonTernary<T> {
     }
 }
 
+// See src/tristate.rs for map/and_then/as_ref/as_mut/unwrap_or/unwrap_or_else/value/into_option/into_nested/from_nested
+crate::impl_tristate_combinators!(JsonTernary);
+
 // https://stackoverflow.com/a/44332837
 impl<T> Default for JsonTernary<T> {
     fn default() -> Self {
@@ -141,6 +144,35 @@ mod tests {
         serde_and_verify(&record_ref, json_ref);
     }
 
+    #[test]
+    pub fn test_map_transforms_value_and_leaves_null_absent() {
+        assert_eq!(JsonTernary::Value(2).map(|v| v * 10), JsonTernary::Value(20));
+        assert_eq!(JsonTernary::<i32>::Null.map(|v| v * 10), JsonTernary::Null);
+        assert_eq!(JsonTernary::<i32>::Absent.map(|v| v * 10), JsonTernary::Absent);
+    }
+
+    #[test]
+    pub fn test_unwrap_or_and_into_option() {
+        assert_eq!(JsonTernary::Value(2).unwrap_or(0), 2);
+        assert_eq!(JsonTernary::<i32>::Null.unwrap_or(0), 0);
+        assert_eq!(JsonTernary::Value(2).into_option(), Some(2));
+        assert_eq!(JsonTernary::<i32>::Absent.into_option(), None);
+    }
+
+    #[test]
+    pub fn test_into_nested_distinguishes_null_from_absent() {
+        assert_eq!(JsonTernary::Value(2).into_nested(), Some(Some(2)));
+        assert_eq!(JsonTernary::<i32>::Null.into_nested(), Some(None));
+        assert_eq!(JsonTernary::<i32>::Absent.into_nested(), None);
+    }
+
+    #[test]
+    pub fn test_from_nested_round_trips_into_nested() {
+        assert_eq!(JsonTernary::from_nested(Some(Some(2))), JsonTernary::Value(2));
+        assert_eq!(JsonTernary::<i32>::from_nested(Some(None)), JsonTernary::Null);
+        assert_eq!(JsonTernary::<i32>::from_nested(None), JsonTernary::Absent);
+    }
+
     fn serde_and_verify(record_ref: &Record, json_ref: &str) {
         let json = serde_json::to_string(&record_ref);
         assert!(json.is_ok());
@@ -0,0 +1,10 @@
+pub mod tristate;
+pub mod json_patch;
+pub mod json_ternary;
+pub mod apply;
+pub mod merge_patch;
+pub mod versioned;
+pub mod incoming;
+
+pub use apply::Apply;
+pub use rust_json_patch_derive::Patchable;
@@ -0,0 +1,139 @@
+//! RFC 7386 JSON Merge Patch (https://datatracker.ietf.org/doc/html/rfc7386).
+//!
+//! `JsonPatch<T>`/`JsonTernary<T>` express the Value/Null/Absent tri-state for a
+//! single flat field. `merge_patch` generalizes the same three cases to an
+//! arbitrarily nested `serde_json::Value`: a `null` in the patch removes the
+//! key (`Null`), a present key recurses or replaces (`Value`), and a key that
+//! is missing from the patch object leaves the target untouched (`Absent`).
+
+use serde_json::{Map, Value};
+
+/// Applies `patch` onto `target` in place, following RFC 7386 semantics.
+pub fn merge_patch(target: &mut Value, patch: &Value) {
+    match patch {
+        Value::Object(patch_map) => {
+            if !target.is_object() {
+                *target = Value::Object(Map::new());
+            }
+            let target_map = target.as_object_mut().unwrap();
+            for (key, value) in patch_map {
+                if value.is_null() {
+                    target_map.remove(key);
+                } else {
+                    let entry = target_map.entry(key.clone()).or_insert(Value::Null);
+                    merge_patch(entry, value);
+                }
+            }
+        }
+        _ => *target = patch.clone(),
+    }
+}
+
+/// Computes the minimal merge patch that turns `original` into `updated`,
+/// i.e. `merge_patch(&mut original.clone(), &diff(original, updated))` yields
+/// `updated` (modulo key order) *as long as `updated` contains no explicit
+/// JSON `null` values*. Per RFC 7386, `null` in a patch always means
+/// "remove this key", so a field that is genuinely set to `null` in
+/// `updated` is emitted as `null` in the returned patch, and applying that
+/// patch removes the key instead of setting it to `null`.
+pub fn diff(original: &Value, updated: &Value) -> Value {
+    match (original, updated) {
+        (Value::Object(original_map), Value::Object(updated_map)) => {
+            let mut patch = Map::new();
+            for (key, original_value) in original_map {
+                match updated_map.get(key) {
+                    Some(updated_value) => {
+                        if original_value != updated_value {
+                            patch.insert(key.clone(), diff(original_value, updated_value));
+                        }
+                    }
+                    None => {
+                        patch.insert(key.clone(), Value::Null);
+                    }
+                }
+            }
+            for (key, updated_value) in updated_map {
+                if !original_map.contains_key(key) {
+                    patch.insert(key.clone(), updated_value.clone());
+                }
+            }
+            Value::Object(patch)
+        }
+        _ => updated.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, merge_patch};
+    use serde_json::json;
+
+    #[test]
+    fn test_merge_patch_overwrites_scalar_and_removes_nested_null() {
+        let mut target = json!({"a": "b", "c": {"d": "e", "f": "g"}});
+        let patch = json!({"a": "z", "c": {"f": null}});
+        merge_patch(&mut target, &patch);
+        assert_eq!(target, json!({"a": "z", "c": {"d": "e"}}));
+    }
+
+    #[test]
+    fn test_merge_patch_replaces_non_object_target_with_nested_object() {
+        let mut target = json!({"a": [1, 2]});
+        let patch = json!({"a": {"b": "c"}});
+        merge_patch(&mut target, &patch);
+        assert_eq!(target, json!({"a": {"b": "c"}}));
+    }
+
+    #[test]
+    fn test_merge_patch_null_removes_key() {
+        let mut target = json!({"a": "b", "c": "d"});
+        let patch = json!({"a": null});
+        merge_patch(&mut target, &patch);
+        assert_eq!(target, json!({"c": "d"}));
+    }
+
+    #[test]
+    fn test_merge_patch_non_object_patch_replaces_whole_target() {
+        let mut target = json!({"a": "b"});
+        let patch = json!(["c"]);
+        merge_patch(&mut target, &patch);
+        assert_eq!(target, json!(["c"]));
+    }
+
+    #[test]
+    fn test_diff_round_trips_through_merge_patch() {
+        let original = json!({"a": "b", "c": {"d": "e", "f": "g"}, "keep": 1});
+        let updated = json!({"a": "z", "c": {"d": "e"}, "keep": 1, "new": true});
+
+        let patch = diff(&original, &updated);
+
+        let mut applied = original.clone();
+        merge_patch(&mut applied, &patch);
+        assert_eq!(applied, updated);
+    }
+
+    #[test]
+    fn test_diff_emits_null_for_removed_keys() {
+        let original = json!({"a": "b", "c": "d"});
+        let updated = json!({"a": "b"});
+        assert_eq!(diff(&original, &updated), json!({"c": null}));
+    }
+
+    #[test]
+    fn test_diff_cannot_express_an_explicit_null_value() {
+        // RFC 7386 has no way to say "set this key to null" in a merge patch,
+        // since null in the patch always means "remove this key". So an
+        // explicit null in `updated` is applied as a removal: the result is
+        // neither `original` nor `updated`, but `original` without the key.
+        let original = json!({"a": "b"});
+        let updated = json!({"a": null});
+
+        let patch = diff(&original, &updated);
+        assert_eq!(patch, json!({"a": null}));
+
+        let mut applied = original.clone();
+        merge_patch(&mut applied, &patch);
+        assert_ne!(applied, updated);
+        assert_eq!(applied, json!({}));
+    }
+}
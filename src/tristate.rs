@@ -0,0 +1,92 @@
+//! Shared combinator surface for the tri-state `JsonPatch`/`JsonTernary`
+//! enums. Both types are structurally identical (`Value`/`Null`/`Absent`), so
+//! the combinators are generated once via macro and invoked from each enum's
+//! own module, keeping the two in lockstep instead of duplicating a dozen
+//! methods by hand.
+#[macro_export]
+macro_rules! impl_tristate_combinators {
+    ($name:ident) => {
+        impl<T> $name<T> {
+            pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> $name<U> {
+                match self {
+                    $name::Value(v) => $name::Value(f(v)),
+                    $name::Null => $name::Null,
+                    $name::Absent => $name::Absent,
+                }
+            }
+
+            pub fn and_then<U, F: FnOnce(T) -> $name<U>>(self, f: F) -> $name<U> {
+                match self {
+                    $name::Value(v) => f(v),
+                    $name::Null => $name::Null,
+                    $name::Absent => $name::Absent,
+                }
+            }
+
+            pub fn as_ref(&self) -> $name<&T> {
+                match self {
+                    $name::Value(v) => $name::Value(v),
+                    $name::Null => $name::Null,
+                    $name::Absent => $name::Absent,
+                }
+            }
+
+            pub fn as_mut(&mut self) -> $name<&mut T> {
+                match self {
+                    $name::Value(v) => $name::Value(v),
+                    $name::Null => $name::Null,
+                    $name::Absent => $name::Absent,
+                }
+            }
+
+            pub fn unwrap_or(self, default: T) -> T {
+                match self {
+                    $name::Value(v) => v,
+                    $name::Null | $name::Absent => default,
+                }
+            }
+
+            pub fn unwrap_or_else<F: FnOnce() -> T>(self, f: F) -> T {
+                match self {
+                    $name::Value(v) => v,
+                    $name::Null | $name::Absent => f(),
+                }
+            }
+
+            pub fn value(&self) -> Option<&T> {
+                match self {
+                    $name::Value(v) => Some(v),
+                    $name::Null | $name::Absent => None,
+                }
+            }
+
+            pub fn into_option(self) -> Option<T> {
+                match self {
+                    $name::Value(v) => Some(v),
+                    $name::Null | $name::Absent => None,
+                }
+            }
+
+            /// Lossless round-trip into `Option<Option<T>>`: `Absent => None`,
+            /// `Null => Some(None)`, `Value(v) => Some(Some(v))`. Unlike
+            /// `into_option`, this is the only mapping that does not collapse
+            /// "field omitted" and "field set to null" into the same value.
+            pub fn into_nested(self) -> Option<Option<T>> {
+                match self {
+                    $name::Value(v) => Some(Some(v)),
+                    $name::Null => Some(None),
+                    $name::Absent => None,
+                }
+            }
+
+            /// Inverse of [`Self::into_nested`].
+            pub fn from_nested(nested: Option<Option<T>>) -> Self {
+                match nested {
+                    Some(Some(v)) => $name::Value(v),
+                    Some(None) => $name::Null,
+                    None => $name::Absent,
+                }
+            }
+        }
+    };
+}
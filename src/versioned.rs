@@ -0,0 +1,165 @@
+//! Versioned patch events with automatic schema migration.
+//!
+//! A persisted queue of `JsonPatch`/`JsonTernary` events outlives any single
+//! struct layout. `Schema` links each version to the one it evolved from, and
+//! `Versioned::parse` deserializes a `{"version": n, ...}` document at its own
+//! version `n`, then folds the `Into` conversions forward one step at a time
+//! until it reaches the version the caller asked for.
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::fmt;
+
+/// A schema version in a migration chain.
+///
+/// `Prev` is the schema this version evolved from; `VERSION` increments along
+/// that chain by default, and the required `Prev: Into<Self>` carries out the
+/// actual per-step migration so `Versioned::parse` never needs to know about
+/// any version but the one it was asked for.
+///
+/// The first real version in a chain sets `Prev = SchemaV0`. Since `SchemaV0`
+/// carries no data, that version must still provide its own
+/// `From<SchemaV0>` (reached for a document with `"version": 0`, or with no
+/// `"version"` field when `UNVERSIONED_V0` is set); typically this is
+/// `Self::default()`.
+pub trait Schema: DeserializeOwned {
+    type Prev: Schema + Into<Self>;
+    const VERSION: u32 = <Self::Prev as Schema>::VERSION + 1;
+
+    /// If `true`, a document with no `"version"` field is treated as version
+    /// 0 instead of failing with [`Error::MissingVersion`].
+    const UNVERSIONED_V0: bool = false;
+}
+
+/// Terminates a `Schema` chain: version 0, with nothing before it.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SchemaV0;
+
+impl<'de> serde::Deserialize<'de> for SchemaV0 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        // The base schema carries no data; still consume the value so that
+        // `parse` can fall back to it for a genuinely empty/unversioned document.
+        Value::deserialize(deserializer).map(|_| SchemaV0)
+    }
+}
+
+impl Schema for SchemaV0 {
+    type Prev = SchemaV0;
+    const VERSION: u32 = 0;
+}
+
+#[derive(Debug)]
+pub enum Error {
+    MissingVersion,
+    UnknownVersion(u32),
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingVersion => write!(f, "document has no \"version\" field"),
+            Error::UnknownVersion(v) => write!(f, "no schema known for version {}", v),
+            Error::Json(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Json(err)
+    }
+}
+
+/// Parses a `{"version": n, ...}` document, migrating it up to schema `T`.
+pub struct Versioned<T>(std::marker::PhantomData<T>);
+
+impl<T: Schema> Versioned<T> {
+    pub fn parse(input: &str) -> Result<T, Error> {
+        let value: Value = serde_json::from_str(input)?;
+        let version = match value.get("version").and_then(Value::as_u64) {
+            Some(version) => version as u32,
+            None if T::UNVERSIONED_V0 => 0,
+            None => return Err(Error::MissingVersion),
+        };
+        migrate::<T>(&value, version)
+    }
+}
+
+fn migrate<T: Schema>(value: &Value, version: u32) -> Result<T, Error> {
+    if version == T::VERSION {
+        Ok(serde_json::from_value(value.clone())?)
+    } else if version < T::VERSION {
+        let prev = migrate::<T::Prev>(value, version)?;
+        Ok(prev.into())
+    } else {
+        Err(Error::UnknownVersion(version))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, Schema, SchemaV0, Versioned};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+    struct PersonEventV1 {
+        name: String,
+    }
+
+    impl Schema for PersonEventV1 {
+        type Prev = SchemaV0;
+    }
+
+    impl From<SchemaV0> for PersonEventV1 {
+        fn from(_: SchemaV0) -> Self {
+            Self::default()
+        }
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct PersonEventV2 {
+        name: String,
+        family_name: String,
+    }
+
+    impl Schema for PersonEventV2 {
+        type Prev = PersonEventV1;
+    }
+
+    impl From<PersonEventV1> for PersonEventV2 {
+        fn from(prev: PersonEventV1) -> Self {
+            PersonEventV2 { name: prev.name, family_name: String::new() }
+        }
+    }
+
+    #[test]
+    fn test_parse_at_current_version_is_passthrough() {
+        let json = r#"{"version":2,"name":"John","family_name":"Doe"}"#;
+        let event = Versioned::<PersonEventV2>::parse(json).unwrap();
+        assert_eq!(event, PersonEventV2 { name: String::from("John"), family_name: String::from("Doe") });
+    }
+
+    #[test]
+    fn test_parse_migrates_older_version_forward() {
+        let json = r#"{"version":1,"name":"John"}"#;
+        let event = Versioned::<PersonEventV2>::parse(json).unwrap();
+        assert_eq!(event, PersonEventV2 { name: String::from("John"), family_name: String::new() });
+    }
+
+    #[test]
+    fn test_parse_missing_version_errors_by_default() {
+        let json = r#"{"name":"John"}"#;
+        let err = Versioned::<PersonEventV1>::parse(json).unwrap_err();
+        assert!(matches!(err, Error::MissingVersion));
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_future_version() {
+        let json = r#"{"version":99,"name":"John"}"#;
+        let err = Versioned::<PersonEventV1>::parse(json).unwrap_err();
+        assert!(matches!(err, Error::UnknownVersion(99)));
+    }
+}
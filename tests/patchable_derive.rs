@@ -0,0 +1,63 @@
+use rust_json_patch::apply::Apply;
+use rust_json_patch::json_patch::JsonPatch;
+use rust_json_patch::json_ternary::JsonTernary;
+use rust_json_patch::Patchable;
+
+#[derive(Debug, Default, PartialEq)]
+struct PersonRecord {
+    name: String,
+    family_name: String,
+    nickname: String,
+    age: u32,
+}
+
+#[derive(Patchable)]
+#[patch(target = "PersonRecord")]
+struct PersonEvent {
+    name: JsonPatch<String>,
+
+    #[patch(rename = "family_name")]
+    last_name: JsonPatch<String>,
+
+    nickname: JsonTernary<String>,
+
+    #[patch(skip)]
+    #[allow(dead_code)]
+    age: JsonPatch<u32>,
+}
+
+#[test]
+fn derived_apply_overwrites_on_value() {
+    let mut record = PersonRecord::default();
+    let event = PersonEvent {
+        name: JsonPatch::Value(String::from("John")),
+        last_name: JsonPatch::Value(String::from("Doe")),
+        nickname: JsonTernary::Value(String::from("Johnny")),
+        age: JsonPatch::Value(42),
+    };
+
+    record.apply(&event);
+
+    assert_eq!(
+        record,
+        PersonRecord { name: String::from("John"), family_name: String::from("Doe"), nickname: String::from("Johnny"), age: 0 }
+    );
+}
+
+#[test]
+fn derived_apply_resets_on_null_and_leaves_absent_and_skip_untouched() {
+    let mut record = PersonRecord { name: String::from("John"), family_name: String::from("Doe"), nickname: String::from("Johnny"), age: 7 };
+    let event = PersonEvent {
+        name: JsonPatch::Absent,
+        last_name: JsonPatch::Null,
+        nickname: JsonTernary::Null,
+        age: JsonPatch::Value(99), // skipped: must not reach the record
+    };
+
+    record.apply(&event);
+
+    assert_eq!(
+        record,
+        PersonRecord { name: String::from("John"), family_name: String::new(), nickname: String::new(), age: 7 }
+    );
+}